@@ -0,0 +1,124 @@
+//! This module provides an implementation of streams as asynchronous overeager receivers of messages.
+//! Here 'overeager' means that one message is always received in advance.
+//!
+//! Unlike [`OvereagerReceiver`](super::overeager_receivers::OvereagerReceiver), [`AsyncOvereagerReceiver<X>`]
+//! does not implement [`Stream<X>`](super::Stream), whose `tail` blocks the current thread. Instead it offers
+//! an `async fn tail` of its own so rspl processors can be driven cooperatively by an executor such as
+//! `tokio` or `async-std`, without dedicating an OS thread to each stream.
+
+use async_channel::{bounded, unbounded, Receiver, Sender};
+
+/// [`AsyncOvereagerReceiver<X>`] abstracts asynchronous receivers of messages of type `X` which always buffer one message.
+pub struct AsyncOvereagerReceiver<X> {
+    /// overeagerly received message
+    message: X,
+    /// receiver of messages
+    receiver: Receiver<X>,
+}
+
+impl<X> AsyncOvereagerReceiver<X> {
+    /// Create a channel with an asynchronous overeager receiver instead of a synchronous one.
+    /// - `cap` is the number of messages the channel can hold where `0` means it can hold any number of messages.
+    /// - `message` is an initial placeholder for what the overeager receiver overeagerly receives.
+    ///
+    /// # Examples
+    ///
+    /// Creating a stream with head `true` and tail whatever is passed by `tx`:
+    ///
+    /// ```
+    /// let (tx, stream) = rspl::streams::async_overeager_receivers::AsyncOvereagerReceiver::channel(0, true);
+    /// ```
+    pub fn channel(cap: usize, message: X) -> (Sender<X>, Self) {
+        let (tx, receiver) = if cap > 0 { bounded(cap) } else { unbounded() };
+        (tx, Self { message, receiver })
+    }
+
+    /// Make the message buffer of `self` the head.
+    pub fn head(&self) -> &X {
+        &self.message
+    }
+
+    /// Waits, without blocking the current thread, until it can make `self` with an updated
+    /// message buffer the tail.
+    ///
+    /// # Panics
+    ///
+    /// A panic is caused if the channel becomes disconnected.
+    pub async fn tail(mut self) -> Self {
+        self.message = self.receiver.recv().await.unwrap();
+        self
+    }
+
+    /// Try to asynchronously make `self` with an updated message buffer the tail.
+    ///
+    /// Waits, without blocking the current thread, until either a new message arrives or the
+    /// channel becomes disconnected. On disconnection `self` is handed back unchanged as
+    /// `Err(self)`, still holding the last overeagerly received message as its head, so an async
+    /// processor can detect end-of-stream as a normal control-flow event instead of a panic.
+    pub async fn try_tail(mut self) -> Result<Self, Self> {
+        match self.receiver.recv().await {
+            Ok(message) => {
+                self.message = message;
+                Ok(self)
+            }
+            Err(_) => Err(self),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[async_std::test]
+    async fn test_async_overeager_channel() {
+        let (tx, stream) = AsyncOvereagerReceiver::channel(1, false);
+        tx.try_send(true).unwrap();
+        assert!(!stream.head());
+        assert!(stream.tail().await.head());
+    }
+
+    #[async_std::test]
+    async fn test_head() {
+        let (_, rx) = async_channel::unbounded();
+        let stream = AsyncOvereagerReceiver {
+            message: true,
+            receiver: rx,
+        };
+        assert!(stream.head());
+    }
+
+    #[async_std::test]
+    async fn test_tail() {
+        let (tx, rx) = async_channel::unbounded();
+        let stream = AsyncOvereagerReceiver {
+            message: false,
+            receiver: rx,
+        };
+        tx.try_send(true).unwrap();
+        assert!(stream.tail().await.head());
+    }
+
+    #[async_std::test]
+    async fn test_try_tail_ok() {
+        let (tx, rx) = async_channel::unbounded();
+        let stream = AsyncOvereagerReceiver {
+            message: false,
+            receiver: rx,
+        };
+        tx.try_send(true).unwrap();
+        assert!(stream.try_tail().await.unwrap().head());
+    }
+
+    #[async_std::test]
+    async fn test_try_tail_err() {
+        let (tx, rx) = async_channel::unbounded::<bool>();
+        let stream = AsyncOvereagerReceiver {
+            message: false,
+            receiver: rx,
+        };
+        drop(tx);
+        let stream = stream.try_tail().await.unwrap_err();
+        assert!(!stream.head());
+    }
+}
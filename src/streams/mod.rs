@@ -0,0 +1,13 @@
+//! This module provides traits and implementations of streams, i.e. (conceptually) infinite sequences of messages.
+
+pub mod async_overeager_receivers;
+pub mod overeager_receivers;
+
+/// [`Stream<X>`] abstracts (conceptually) infinite sequences of messages of type `X`.
+pub trait Stream<X> {
+    /// Return the first message of `self`.
+    fn head(&self) -> &X;
+
+    /// Return `self` without its first message.
+    fn tail(self) -> Self;
+}
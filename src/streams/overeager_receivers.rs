@@ -3,8 +3,10 @@
 
 use super::Stream;
 
+use std::time::Duration;
+
 use crossbeam::channel::{bounded, unbounded};
-use crossbeam::channel::{Receiver, Sender};
+use crossbeam::channel::{Receiver, RecvTimeoutError, Select, Sender};
 
 /// [`OvereagerReceiver<X>`] abstracts receivers of messages of type `X` which always buffer one message.
 pub struct OvereagerReceiver<X> {
@@ -12,6 +14,8 @@ pub struct OvereagerReceiver<X> {
     message: X,
     /// receiver of messages
     receiver: Receiver<X>,
+    /// a sender kept alive internally so the channel survives the drop of all external senders
+    keep_alive: Option<Sender<X>>,
 }
 
 impl<X> OvereagerReceiver<X> {
@@ -28,7 +32,72 @@ impl<X> OvereagerReceiver<X> {
     /// ```
     pub fn channel(cap: usize, message: X) -> (Sender<X>, Self) {
         let (tx, receiver) = if cap > 0 { bounded(cap) } else { unbounded() };
-        (tx, Self { message, receiver })
+        (
+            tx,
+            Self {
+                message,
+                receiver,
+                keep_alive: None,
+            },
+        )
+    }
+
+    /// Create a channel with an overeager receiver which survives the drop of all its senders.
+    /// - `cap` is the number of messages the channel can hold where `0` means it can hold any number of messages.
+    /// - `message` is an initial placeholder for what the overeager receiver overeagerly receives.
+    ///
+    /// Unlike [`channel`](OvereagerReceiver::channel), the returned receiver keeps a sender of its
+    /// own alive internally, so it never disconnects even if every external [`Sender<X>`] returned
+    /// by `channel_reconnectable` or minted later via [`new_sender`](OvereagerReceiver::new_sender)
+    /// is dropped. [`tail`](Stream::tail) and [`try_tail`](OvereagerReceiver::try_tail) then simply
+    /// park until a supervised producer reconnects, rather than treating the temporary absence of
+    /// senders as permanent end-of-stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let (tx, stream) = rspl::streams::overeager_receivers::OvereagerReceiver::channel_reconnectable(0, true);
+    /// ```
+    pub fn channel_reconnectable(cap: usize, message: X) -> (Sender<X>, Self) {
+        let (tx, receiver) = if cap > 0 { bounded(cap) } else { unbounded() };
+        (
+            tx.clone(),
+            Self {
+                message,
+                receiver,
+                keep_alive: Some(tx),
+            },
+        )
+    }
+
+    /// Mint a fresh [`Sender<X>`] for `self`.
+    ///
+    /// # Panics
+    ///
+    /// A panic is caused if `self` was not created with
+    /// [`channel_reconnectable`](OvereagerReceiver::channel_reconnectable).
+    pub fn new_sender(&self) -> Sender<X> {
+        self.keep_alive
+            .clone()
+            .expect("OvereagerReceiver is not reconnectable; create it with channel_reconnectable")
+    }
+}
+
+impl<X> OvereagerReceiver<X> {
+    /// Try to make `self` with an updated message buffer the tail.
+    ///
+    /// Blocks the current thread until either a new message arrives or the channel becomes
+    /// disconnected. On disconnection `self` is handed back unchanged as `Err(self)`, still
+    /// holding the last overeagerly received message as its head, so that callers can drain
+    /// that final head and terminate cleanly instead of panicking.
+    pub fn try_tail(mut self) -> Result<Self, Self> {
+        match self.receiver.recv() {
+            Ok(message) => {
+                self.message = message;
+                Ok(self)
+            }
+            Err(_) => Err(self),
+        }
     }
 }
 
@@ -49,6 +118,172 @@ impl<X> Stream<X> for OvereagerReceiver<X> {
     }
 }
 
+/// [`TimeoutOvereagerReceiver<X>`] abstracts receivers of messages of type `X` which always buffer
+/// one message and fall back to a tick message instead of blocking forever when idle.
+pub struct TimeoutOvereagerReceiver<X> {
+    /// overeagerly received message
+    message: X,
+    /// receiver of messages
+    receiver: Receiver<X>,
+    /// maximum time to wait for a new message before ticking
+    timeout: Duration,
+    /// placeholder message produced whenever `timeout` elapses without a new message arriving
+    tick: X,
+}
+
+impl<X: Clone> TimeoutOvereagerReceiver<X> {
+    /// Create a channel with an overeager receiver that ticks instead of blocking forever.
+    /// - `cap` is the number of messages the channel can hold where `0` means it can hold any number of messages.
+    /// - `message` is an initial placeholder for what the overeager receiver overeagerly receives, and also the
+    ///   "tick" message the receiver falls back on whenever `timeout` elapses without a new message arriving.
+    /// - `timeout` is the maximum time [`tail`](Stream::tail) and [`try_tail`](TimeoutOvereagerReceiver::try_tail)
+    ///   wait for a new message before ticking.
+    ///
+    /// # Examples
+    ///
+    /// Creating a stream which ticks `false` every second until `tx` sends `true`:
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// let (tx, stream) = rspl::streams::overeager_receivers::TimeoutOvereagerReceiver::channel_timeout(
+    ///     0,
+    ///     false,
+    ///     Duration::from_secs(1),
+    /// );
+    /// ```
+    pub fn channel_timeout(cap: usize, message: X, timeout: Duration) -> (Sender<X>, Self) {
+        let (tx, receiver) = if cap > 0 { bounded(cap) } else { unbounded() };
+        (
+            tx,
+            Self {
+                message: message.clone(),
+                receiver,
+                timeout,
+                tick: message,
+            },
+        )
+    }
+
+    /// Try to make `self` with an updated message buffer the tail.
+    ///
+    /// Blocks the current thread until either a new message arrives, the channel becomes
+    /// disconnected, or `timeout` elapses. On disconnection `self` is handed back unchanged as
+    /// `Err(self)`. On timeout `self` ticks forward to the tick message instead of waiting any
+    /// further.
+    pub fn try_tail(mut self) -> Result<Self, Self> {
+        match self.receiver.recv_timeout(self.timeout) {
+            Ok(message) => {
+                self.message = message;
+                Ok(self)
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                self.message = self.tick.clone();
+                Ok(self)
+            }
+            Err(RecvTimeoutError::Disconnected) => Err(self),
+        }
+    }
+}
+
+impl<X: Clone> Stream<X> for TimeoutOvereagerReceiver<X> {
+    /// Make the message buffer of `self` the head.
+    fn head(&self) -> &X {
+        &self.message
+    }
+
+    /// Blocks the current thread until it can make `self` with an updated message buffer the tail.
+    ///
+    /// If no message arrives before `timeout` elapses, the tick message is used instead of
+    /// blocking any further.
+    ///
+    /// # Panics
+    ///
+    /// A panic is caused if the channel becomes disconnected.
+    fn tail(mut self) -> Self {
+        self.message = match self.receiver.recv_timeout(self.timeout) {
+            Ok(message) => message,
+            Err(RecvTimeoutError::Timeout) => self.tick.clone(),
+            Err(RecvTimeoutError::Disconnected) => panic!("channel is disconnected"),
+        };
+        self
+    }
+}
+
+/// [`MergedOvereagerReceiver<X>`] merges several [`OvereagerReceiver<X>`]s into a single stream by
+/// always advancing whichever underlying receiver becomes ready first.
+pub struct MergedOvereagerReceiver<X> {
+    /// one overeagerly received message per merged input
+    receivers: Vec<OvereagerReceiver<X>>,
+    /// index into `receivers` of the input which was selected last
+    current: usize,
+}
+
+impl<X> MergedOvereagerReceiver<X> {
+    /// Merge `receivers` into a single stream.
+    ///
+    /// # Panics
+    ///
+    /// A panic is caused if `receivers` is empty.
+    pub fn new(receivers: Vec<OvereagerReceiver<X>>) -> Self {
+        assert!(
+            !receivers.is_empty(),
+            "cannot merge an empty list of receivers"
+        );
+        Self {
+            receivers,
+            current: 0,
+        }
+    }
+}
+
+impl<X> Stream<X> for MergedOvereagerReceiver<X> {
+    /// Make the overeagerly received message of whichever input was selected last the head.
+    fn head(&self) -> &X {
+        self.receivers[self.current].head()
+    }
+
+    /// Blocks the current thread until it can make `self` with an updated message buffer the tail.
+    ///
+    /// Advances from whichever underlying receiver is ready first, leaving the other inputs
+    /// untouched. A merged input which becomes disconnected is dropped from `self` rather than
+    /// ending the merged stream, so the remaining inputs keep being selected over.
+    ///
+    /// # Panics
+    ///
+    /// A panic is caused if every merged input becomes disconnected.
+    fn tail(mut self) -> Self {
+        loop {
+            let selected = {
+                let mut select = Select::new();
+                for receiver in &self.receivers {
+                    select.recv(&receiver.receiver);
+                }
+                let oper = select.select();
+                let index = oper.index();
+                oper.recv(&self.receivers[index].receiver)
+                    .map(|message| (index, message))
+                    .map_err(|_| index)
+            };
+            match selected {
+                Ok((index, message)) => {
+                    self.receivers[index].message = message;
+                    self.current = index;
+                    return self;
+                }
+                Err(index) => {
+                    self.receivers.remove(index);
+                    assert!(
+                        !self.receivers.is_empty(),
+                        "every merged receiver is disconnected"
+                    );
+                    self.current = self.current.min(self.receivers.len() - 1);
+                }
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -80,6 +315,7 @@ mod tests {
         let stream = OvereagerReceiver {
             message: true,
             receiver: rx,
+            keep_alive: None,
         };
         assert!(stream.head());
     }
@@ -90,8 +326,113 @@ mod tests {
         let stream = OvereagerReceiver {
             message: false,
             receiver: rx,
+            keep_alive: None,
         };
         enqueue!(tx, [true]);
         assert!(stream.tail().head());
     }
+
+    #[test]
+    fn test_try_tail_ok() {
+        let (tx, rx) = channel();
+        let stream = OvereagerReceiver {
+            message: false,
+            receiver: rx,
+            keep_alive: None,
+        };
+        enqueue!(tx, [true]);
+        assert!(stream.try_tail().unwrap().head());
+    }
+
+    #[test]
+    fn test_try_tail_err() {
+        let (tx, rx) = channel();
+        let stream = OvereagerReceiver {
+            message: false,
+            receiver: rx,
+            keep_alive: None,
+        };
+        drop(tx);
+        let stream = stream.try_tail().unwrap_err();
+        assert!(!stream.head());
+    }
+
+    #[test]
+    fn test_channel_timeout_ticks_on_idle() {
+        let (_tx, stream) =
+            TimeoutOvereagerReceiver::channel_timeout(0, false, Duration::from_millis(1));
+        assert_head_eq!(stream, false);
+        assert_tail_starts_with!(stream, [false]);
+    }
+
+    #[test]
+    fn test_channel_timeout_forwards_messages() {
+        let (tx, stream) =
+            TimeoutOvereagerReceiver::channel_timeout(1, false, Duration::from_secs(1));
+        enqueue!(tx, [true]);
+        assert_tail_starts_with!(stream, [true]);
+    }
+
+    #[test]
+    fn test_channel_timeout_try_tail_err() {
+        let (tx, stream) =
+            TimeoutOvereagerReceiver::channel_timeout(0, false, Duration::from_secs(1));
+        drop(tx);
+        let stream = stream.try_tail().unwrap_err();
+        assert!(!stream.head());
+    }
+
+    #[test]
+    fn test_merged_head() {
+        let (_, a) = OvereagerReceiver::channel(0, 1);
+        let (_, b) = OvereagerReceiver::channel(0, 2);
+        let stream = MergedOvereagerReceiver::new(vec![a, b]);
+        assert_head_eq!(stream, 1);
+    }
+
+    #[test]
+    fn test_merged_tail_selects_ready_input() {
+        let (tx_a, a) = OvereagerReceiver::channel(1, 0);
+        let (_tx_b, b) = OvereagerReceiver::channel(1, 0);
+        let stream = MergedOvereagerReceiver::new(vec![a, b]);
+        enqueue!(tx_a, [1]);
+        assert_tail_starts_with!(stream, [1]);
+    }
+
+    #[test]
+    fn test_merged_tail_skips_disconnected_input() {
+        let (tx_a, a) = OvereagerReceiver::channel(1, 0);
+        let (tx_b, b) = OvereagerReceiver::channel(1, 0);
+        let stream = MergedOvereagerReceiver::new(vec![a, b]);
+        drop(tx_a);
+        enqueue!(tx_b, [1]);
+        assert_tail_starts_with!(stream, [1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_merged_tail_panics_when_all_disconnected() {
+        let (tx_a, a) = OvereagerReceiver::channel(1, 0);
+        let (tx_b, b) = OvereagerReceiver::channel(1, 0);
+        let stream = MergedOvereagerReceiver::new(vec![a, b]);
+        drop(tx_a);
+        drop(tx_b);
+        stream.tail();
+    }
+
+    #[test]
+    fn test_new_sender_survives_drop_of_original_sender() {
+        let (tx, stream) = OvereagerReceiver::channel_reconnectable(0, false);
+        drop(tx);
+        let tx = stream.new_sender();
+        enqueue!(tx, [true]);
+        assert_tail_starts_with!(stream, [true]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_sender_panics_when_not_reconnectable() {
+        let (_, stream) = OvereagerReceiver::channel(0, false);
+        stream.new_sender();
+    }
 }